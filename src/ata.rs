@@ -5,18 +5,32 @@ use x86_64::instructions::port::{Port, PortReadOnly, PortWriteOnly};
 use core::hint::spin_loop;
 use core::convert::TryInto;
 use core::fmt::Debug;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
 use alloc::vec::Vec;
 use alloc::string::String;
+use crossbeam_queue::ArrayQueue;
+use futures_util::task::AtomicWaker;
 use crate::println;
 
 pub const BLOCK_SIZE: usize = 512;
 
+/// Bound on how many times `Bus::poll` spins before giving up, so a missing
+/// or wedged drive can't hang the kernel forever during detection.
+const POLL_SPIN_BUDGET: u32 = 300_000;
+
 #[derive(Debug)]
 #[repr(u16)]
 enum Command {
     Read = 0x20,
     Write = 0x30,
     Identify = 0xEC,
+    /// LBA48 variants, used once a drive's IDENTIFY data reports 48-bit
+    /// addressing support.
+    ReadExt = 0x24,
+    WriteExt = 0x34,
 }
 
 enum IdentifyResponse {
@@ -38,6 +52,9 @@ enum Status {
 pub struct Bus {
     id: u8,
     irq: u8,
+    /// Whether each of the two drives on this bus reported LBA48 support
+    /// during IDENTIFY, set by `Drive::open` via `set_lba48`.
+    lba48: [bool; 2],
     data: Port<u16>,
     error: PortReadOnly<u8>,
     features: PortWriteOnly<u8>,
@@ -58,6 +75,7 @@ impl Bus {
         Self {
             id,
             irq,
+            lba48: [false, false],
             data: Port::new(io_base),
             error: PortReadOnly::new(io_base + 1),
             features: PortWriteOnly::new(io_base + 1),
@@ -109,41 +127,110 @@ impl Bus {
         self.status().get_bit(Status::Error as usize)
     }
 
+    /// Records whether `drive` supports LBA48 addressing, as reported by
+    /// its IDENTIFY data.
+    fn set_lba48(&mut self, drive: u8, supported: bool) {
+        self.lba48[drive as usize] = supported;
+    }
+
+    /// Spins on `bit` reaching `value`, bailing out after `POLL_SPIN_BUDGET`
+    /// iterations instead of hanging forever on a missing or wedged drive.
     fn poll(&mut self, bit: Status, value: bool) -> Result<(), ()> {
-        while self.status().get_bit(bit as usize) != value {
+        for _ in 0..POLL_SPIN_BUDGET {
+            if self.status().get_bit(bit as usize) == value {
+                return Ok(());
+            }
             spin_loop();
         }
+        Err(())
+    }
+
+    /// Polls for `bit == value`, and on timeout runs the ATA-4 soft-reset
+    /// recovery sequence before giving up so the bus is left in a clean
+    /// state for the next probe.
+    fn poll_or_recover(&mut self, bit: Status, value: bool) -> Result<(), ()> {
+        if self.poll(bit, value).is_err() {
+            self.recover();
+            return Err(());
+        }
         Ok(())
     }
 
+    /// ATA-4 soft-reset recovery: toggle SRST, then wait for the drive to
+    /// drop BSY and report ready before handing control back.
+    fn recover(&mut self) {
+        self.reset();
+        let _ = self.poll(Status::Busy, false);
+        let _ = self.poll(Status::DeviceReady, true);
+    }
+
     fn select_drive(&mut self, drive: u8) -> Result<(), ()> {
-        self.poll(Status::Busy, false)?;
-        self.poll(Status::DataRequest, false)?;
+        self.poll_or_recover(Status::Busy, false)?;
+        self.poll_or_recover(Status::DataRequest, false)?;
         unsafe {
             // bit 4 -> device
             // bit 5 -> 1
             // bit 7 -> 1
             self.drive.write(0xA0 | (drive << 4))
         }
-        self.poll(Status::Busy, false)?;
-        self.poll(Status::DataRequest, false)?;
+        // mandatory ~400ns settle delay after a drive select
+        for _ in 0..4 {
+            self.status();
+        }
+        self.poll_or_recover(Status::Busy, false)?;
+        self.poll_or_recover(Status::DataRequest, false)?;
         Ok(())
     }
 
-    fn write_command_args(&mut self, drive: u8, block: u32) -> Result<(), ()> {
-        let lba = true;
-        let mut bytes = block.to_le_bytes();
-        bytes[3].set_bit(4, drive > 0);
-        bytes[3].set_bit(5, true);
-        bytes[3].set_bit(6, lba);
-        bytes[3].set_bit(7, true);
-        unsafe {
-            self.sector_count.write(1);
-            self.lba_low.write(bytes[0]);
-            self.lba_mid.write(bytes[1]);
-            self.lba_high.write(bytes[2]);
-            self.drive.write(bytes[3]);
+    /// `sectors` is the raw ATA sector-count register value (0 means 256).
+    /// When `lba48` is set, `block` is programmed as a 48-bit address per
+    /// the ATA-4 48-bit command protocol.
+    fn write_command_args(&mut self, drive: u8, block: u64, sectors: u8, lba48: bool) -> Result<(), ()> {
+        if lba48 {
+            let bytes = block.to_le_bytes();
+            let mut drive_byte = 0u8;
+            drive_byte.set_bit(4, drive > 0);
+            drive_byte.set_bit(5, true);
+            drive_byte.set_bit(6, true);
+            drive_byte.set_bit(7, true);
+            unsafe {
+                // High count byte: a 16-bit count of 0 would mean 65536, so
+                // 256 sectors (low byte 0) is encoded as 0x0100.
+                self.sector_count.write(if sectors == 0 { 1 } else { 0 });
+                self.lba_low.write(bytes[3]);
+                self.lba_mid.write(bytes[4]);
+                self.lba_high.write(bytes[5]);
+                self.sector_count.write(sectors);
+                self.lba_low.write(bytes[0]);
+                self.lba_mid.write(bytes[1]);
+                self.lba_high.write(bytes[2]);
+                self.drive.write(drive_byte);
+            }
+        } else {
+            let mut bytes = (block as u32).to_le_bytes();
+            bytes[3].set_bit(4, drive > 0);
+            bytes[3].set_bit(5, true);
+            bytes[3].set_bit(6, true);
+            bytes[3].set_bit(7, true);
+            unsafe {
+                self.sector_count.write(sectors);
+                self.lba_low.write(bytes[0]);
+                self.lba_mid.write(bytes[1]);
+                self.lba_high.write(bytes[2]);
+                self.drive.write(bytes[3]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `cmd` without waiting for DRQ, for the async path below,
+    /// which awaits the drive's interrupt instead.
+    fn issue_command(&mut self, cmd: Command) -> Result<(), ()> {
+        if self.is_error() {
+            self.recover();
+            return Err(());
         }
+        unsafe { self.command.write(cmd as u8) }
         Ok(())
     }
 
@@ -155,25 +242,36 @@ impl Bus {
             return Err(())
         }
         if self.is_error() {
+            self.recover();
             return Err(())
         }
-        self.poll(Status::Busy, false)?;
-        self.poll(Status::DataRequest, true)?;
+        self.poll_or_recover(Status::Busy, false)?;
+        self.poll_or_recover(Status::DataRequest, true)?;
         Ok(())
     }
 
-    fn setup_pio(&mut self, drive: u8, block: u32) -> Result<(), ()> {
+    fn setup_pio(&mut self, drive: u8, block: u64, sectors: u8) -> Result<(), ()> {
         self.select_drive(drive)?;
-        self.write_command_args(drive, block)?;
+        self.write_command_args(drive, block, sectors, self.lba48[drive as usize])?;
         Ok(())
     }
 
-    fn read(&mut self, drive: u8, block: u32, buf: &mut [u8]) -> Result<(), ()> {
-        self.setup_pio(drive, block)?;
-        self.write_command(Command::Read)?;
-        for chunk in buf.chunks_mut(2) {
-            let data = self.read_data().to_le_bytes();
-            chunk.clone_from_slice(&data);
+    /// Reads `sectors` 512-byte blocks (0 meaning 256) starting at `block`
+    /// into `buf`, which must be exactly `sectors * BLOCK_SIZE` long. The
+    /// drive raises DRQ once per sector, so we re-poll it between each
+    /// sector's 256-word PIO burst rather than only once up front.
+    fn read(&mut self, drive: u8, block: u64, sectors: u8, buf: &mut [u8]) -> Result<(), ()> {
+        self.setup_pio(drive, block, sectors)?;
+        let cmd = if self.lba48[drive as usize] { Command::ReadExt } else { Command::Read };
+        self.write_command(cmd)?;
+        for (i, sector) in buf.chunks_mut(BLOCK_SIZE).enumerate() {
+            if i > 0 {
+                self.poll(Status::DataRequest, true)?;
+            }
+            for chunk in sector.chunks_mut(2) {
+                let data = self.read_data().to_le_bytes();
+                chunk.clone_from_slice(&data);
+            }
         }
         if self.is_error() {
             Err(())
@@ -182,12 +280,20 @@ impl Bus {
         }
     }
 
-    fn write(&mut self, drive: u8, block: u32, buf: &[u8]) -> Result<(), ()> {
-        self.setup_pio(drive, block)?;
-        self.write_command(Command::Write)?;
-        for chunk in buf.chunks(2) {
-            let data = u16::from_le_bytes(chunk.try_into().unwrap());
-            self.write_data(data);
+    /// Writes `sectors` 512-byte blocks (0 meaning 256) from `buf` starting
+    /// at `block`. See `read` for why DRQ is re-polled per sector.
+    fn write(&mut self, drive: u8, block: u64, sectors: u8, buf: &[u8]) -> Result<(), ()> {
+        self.setup_pio(drive, block, sectors)?;
+        let cmd = if self.lba48[drive as usize] { Command::WriteExt } else { Command::Write };
+        self.write_command(cmd)?;
+        for (i, sector) in buf.chunks(BLOCK_SIZE).enumerate() {
+            if i > 0 {
+                self.poll(Status::DataRequest, true)?;
+            }
+            for chunk in sector.chunks(2) {
+                let data = u16::from_le_bytes(chunk.try_into().unwrap());
+                self.write_data(data);
+            }
         }
         if self.is_error() {
             Err(())
@@ -201,7 +307,7 @@ impl Bus {
             return Ok(IdentifyResponse::None);
         }
         self.select_drive(drive)?;
-        self.write_command_args(drive, 0)?;
+        self.write_command_args(drive, 0, 1, false)?;
         if self.write_command(Command::Identify).is_err() {
             if self.status() == 0 {
                 return Ok(IdentifyResponse::None);
@@ -233,7 +339,7 @@ lazy_static! {
 pub struct Drive {
     pub bus: u8,
     pub dsk: u8,
-    blocks: u32,
+    blocks: u64,
     model: String,
     serial: String,
 }
@@ -245,7 +351,22 @@ impl Drive {
             let buf = res.map(u16::to_be_bytes).concat();
             let serial = String::from_utf8_lossy(&buf[20..40]).trim().into();
             let model = String::from_utf8_lossy(&buf[54..94]).trim().into();
-            let blocks = u32::from_be_bytes(buf[120..124].try_into().unwrap()).rotate_left(16);
+
+            // Word 83, bit 10: drive supports the LBA48 feature set.
+            let lba48 = res[83].get_bit(10);
+            let blocks = if lba48 {
+                // Words 100-103: 64-bit user-addressable sector count.
+                res[100] as u64
+                    | (res[101] as u64) << 16
+                    | (res[102] as u64) << 32
+                    | (res[103] as u64) << 48
+            } else {
+                // Words 60-61: 28-bit user-addressable sector count.
+                ((res[61] as u64) << 16) | res[60] as u64
+            };
+
+            buses[bus as usize].set_lba48(dsk, lba48);
+
             Some(Self { bus, dsk, model, serial, blocks })
         } else {
             None
@@ -256,18 +377,18 @@ impl Drive {
         BLOCK_SIZE as u32
     }
 
-    pub fn block_count(&self) -> u32 {
+    pub fn block_count(&self) -> u64 {
         self.blocks
     }
 
-    fn human_readable_size(&self) -> (usize, String) {
-        let size = self.block_size() as usize;
-        let count = self.block_count() as usize;
-        let bytes = size * count;
+    fn human_readable_size(&self) -> (u64, String) {
+        let bytes = self.block_size() as u64 * self.block_count();
         if bytes >> 20 < 1000 {
             (bytes >> 20, String::from("MB"))
-        } else {
+        } else if bytes >> 30 < 1000 {
             (bytes >> 30, String::from("GB"))
+        } else {
+            (bytes >> 40, String::from("TB"))
         }
     }
 }
@@ -284,14 +405,78 @@ pub fn list_drives() -> Vec<Drive> {
     res
 }
 
-pub fn read_ata(bus: u8, drive: u8, block: u32, buf: &mut [u8]) -> Result<(), ()> {
+/// Maximum number of blocks a single ATA command can move, per the 8-bit
+/// sector-count register (256 is encoded as 0).
+const MAX_SECTORS_PER_COMMAND: usize = 256;
+
+/// Reads `buf.len() / BLOCK_SIZE` blocks starting at `block`, chunking the
+/// transfer into at-most-256-sector commands as needed. Takes the bus's
+/// `BusQueue` for the whole call so this can't interleave with an async
+/// transfer parked mid-sector on the same bus.
+pub fn read_ata(bus: u8, drive: u8, block: u64, buf: &mut [u8]) -> Result<(), ()> {
+    assert_eq!(buf.len() % BLOCK_SIZE, 0, "buffer must be a multiple of BLOCK_SIZE");
+    BUS_QUEUES[bus as usize].acquire_blocking()?;
     let mut buses = BUSES.lock();
-    buses[bus as usize].read(drive, block, buf)
+    let mut lba = block;
+    let mut result = Ok(());
+    for chunk in buf.chunks_mut(MAX_SECTORS_PER_COMMAND * BLOCK_SIZE) {
+        let sectors = chunk.len() / BLOCK_SIZE;
+        if let Err(()) = buses[bus as usize].read(drive, lba, sectors as u8, chunk) {
+            result = Err(());
+            break;
+        }
+        lba += sectors as u64;
+    }
+    drop(buses);
+    BUS_QUEUES[bus as usize].release();
+    result
 }
 
-pub fn write_ata(bus: u8, drive: u8, block: u32, buf: &[u8]) -> Result<(), ()> {
+/// Writes `buf.len() / BLOCK_SIZE` blocks starting at `block`, chunking the
+/// transfer into at-most-256-sector commands as needed. See `read_ata`.
+pub fn write_ata(bus: u8, drive: u8, block: u64, buf: &[u8]) -> Result<(), ()> {
+    assert_eq!(buf.len() % BLOCK_SIZE, 0, "buffer must be a multiple of BLOCK_SIZE");
+    BUS_QUEUES[bus as usize].acquire_blocking()?;
     let mut buses = BUSES.lock();
-    buses[bus as usize].write(drive, block, buf)
+    let mut lba = block;
+    let mut result = Ok(());
+    for chunk in buf.chunks(MAX_SECTORS_PER_COMMAND * BLOCK_SIZE) {
+        let sectors = chunk.len() / BLOCK_SIZE;
+        if let Err(()) = buses[bus as usize].write(drive, lba, sectors as u8, chunk) {
+            result = Err(());
+            break;
+        }
+        lba += sectors as u64;
+    }
+    drop(buses);
+    BUS_QUEUES[bus as usize].release();
+    result
+}
+
+/// Number of blocks zeroed per `write_ata` call while erasing.
+const ERASE_CHUNK_SECTORS: u64 = 128;
+
+/// Zero-fills `count` 512-byte blocks starting at `start_block`, logging
+/// progress every 64 chunks and once at the end.
+pub fn erase(bus: u8, dsk: u8, start_block: u64, count: u64) -> Result<(), ()> {
+    let zeros = alloc::vec![0u8; ERASE_CHUNK_SECTORS as usize * BLOCK_SIZE];
+    let mut block = start_block;
+    let mut remaining = count;
+    while remaining > 0 {
+        let sectors = remaining.min(ERASE_CHUNK_SECTORS);
+        write_ata(bus, dsk, block, &zeros[..sectors as usize * BLOCK_SIZE])?;
+        block += sectors;
+        remaining -= sectors;
+        if remaining == 0 || (count - remaining) % (ERASE_CHUNK_SECTORS * 64) == 0 {
+            println!("ATA: erased {}/{} blocks", count - remaining, count);
+        }
+    }
+    Ok(())
+}
+
+/// Zero-fills an entire drive, using its reported block count.
+pub fn erase_drive(drive: &Drive) -> Result<(), ()> {
+    erase(drive.bus, drive.dsk, 0, drive.block_count())
 }
 
 lazy_static! {
@@ -315,3 +500,327 @@ pub fn init() {
         }
     }
 }
+
+// --- Async, interrupt-driven block I/O --------------------------------------
+//
+// Unlike `read_ata`/`write_ata`, these issue the command and yield, resuming
+// once the bus's IRQ (14 primary, 15 secondary) fires via `handle_interrupt`.
+
+/// One waker slot per bus, filled in by whichever task is currently
+/// awaiting that bus's drive interrupt.
+static INTERRUPT_WAKERS: [AtomicWaker; 2] = [AtomicWaker::new(), AtomicWaker::new()];
+
+/// Set by `handle_interrupt`, cleared by the waiting future, so a wakeup
+/// that races ahead of `Future::poll` registering isn't lost.
+static INTERRUPT_PENDING: [AtomicBool; 2] = [AtomicBool::new(false), AtomicBool::new(false)];
+
+/// Records the bus's interrupt and wakes whichever async task is waiting on
+/// it. Call this from the IRQ 14 / IRQ 15 handlers in the IDT.
+///
+/// Deliberately doesn't touch `BUSES`: re-locking it from interrupt context
+/// would deadlock against an in-progress synchronous command on this bus.
+pub fn handle_interrupt(bus: u8) {
+    INTERRUPT_PENDING[bus as usize].store(true, Ordering::Release);
+    INTERRUPT_WAKERS[bus as usize].wake();
+}
+
+/// Bound on how many times `WaitForInterrupt` is polled before giving up on
+/// a lost interrupt or absent drive, mirroring `Bus::poll`'s spin budget.
+const ASYNC_INTERRUPT_POLL_BUDGET: u32 = 1_000_000;
+
+struct WaitForInterrupt {
+    bus: u8,
+    attempts: u32,
+}
+
+impl WaitForInterrupt {
+    fn new(bus: u8) -> Self {
+        Self { bus, attempts: 0 }
+    }
+}
+
+impl Future for WaitForInterrupt {
+    type Output = Result<(), ()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), ()>> {
+        let bus = self.bus as usize;
+        if INTERRUPT_PENDING[bus].swap(false, Ordering::Acquire) {
+            return Poll::Ready(Ok(()));
+        }
+        INTERRUPT_WAKERS[bus].register(cx.waker());
+        if INTERRUPT_PENDING[bus].swap(false, Ordering::Acquire) {
+            INTERRUPT_WAKERS[bus].take();
+            return Poll::Ready(Ok(()));
+        }
+
+        self.attempts += 1;
+        if self.attempts >= ASYNC_INTERRUPT_POLL_BUDGET {
+            INTERRUPT_WAKERS[bus].take();
+            return Poll::Ready(Err(()));
+        }
+        // No interrupt yet and no guarantee one is coming (missing drive,
+        // lost IRQ); keep this task in the run queue so the budget above
+        // actually gets spent instead of waiting on a wakeup that may
+        // never arrive.
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Per-bus FIFO so concurrent filesystem tasks serialize onto one drive
+/// instead of interleaving commands while both are mid-transfer.
+struct BusQueue {
+    locked: AtomicBool,
+    waiters: ArrayQueue<Waker>,
+}
+
+impl BusQueue {
+    fn new() -> Self {
+        Self { locked: AtomicBool::new(false), waiters: ArrayQueue::new(32) }
+    }
+
+    fn try_acquire(&self) -> bool {
+        self.locked.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed).is_ok()
+    }
+
+    fn release(&self) {
+        self.locked.store(false, Ordering::Release);
+        if let Some(waker) = self.waiters.pop() {
+            waker.wake();
+        }
+    }
+
+    /// Blocks the calling (non-async) context until the bus is free, giving
+    /// up after `BLOCKING_ACQUIRE_SPIN_BUDGET` iterations instead of
+    /// spinning forever against a task parked on `WaitForInterrupt` that the
+    /// executor never gets to finish.
+    fn acquire_blocking(&self) -> Result<(), ()> {
+        for _ in 0..BLOCKING_ACQUIRE_SPIN_BUDGET {
+            if self.try_acquire() {
+                return Ok(());
+            }
+            spin_loop();
+        }
+        Err(())
+    }
+}
+
+/// Bound on how many times `BusQueue::acquire_blocking` spins before giving
+/// up, mirroring `WaitForInterrupt`'s and `AcquireBus`'s poll budgets.
+const BLOCKING_ACQUIRE_SPIN_BUDGET: u32 = 1_000_000;
+
+/// Bound on how many times `AcquireBus` is polled before giving up,
+/// mirroring `WaitForInterrupt`'s budget.
+const ASYNC_BUS_QUEUE_POLL_BUDGET: u32 = 1_000_000;
+
+struct AcquireBus {
+    bus: u8,
+    attempts: u32,
+}
+
+impl AcquireBus {
+    fn new(bus: u8) -> Self {
+        Self { bus, attempts: 0 }
+    }
+}
+
+impl Future for AcquireBus {
+    type Output = Result<(), ()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), ()>> {
+        let queue = &BUS_QUEUES[self.bus as usize];
+        if queue.try_acquire() {
+            return Poll::Ready(Ok(()));
+        }
+        let _ = queue.waiters.push(cx.waker().clone());
+        // The lock may have been released between the failed try_acquire
+        // above and pushing our waker; check once more before parking.
+        if queue.try_acquire() {
+            return Poll::Ready(Ok(()));
+        }
+
+        self.attempts += 1;
+        if self.attempts >= ASYNC_BUS_QUEUE_POLL_BUDGET {
+            return Poll::Ready(Err(()));
+        }
+        // The waiter queue may have been full (silently dropping our
+        // waker above); keep this task scheduled so the budget is actually
+        // spent instead of relying solely on a wakeup that could be lost.
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+lazy_static! {
+    static ref BUS_QUEUES: [BusQueue; 2] = [BusQueue::new(), BusQueue::new()];
+}
+
+/// Issues a single chunked read, awaiting the bus interrupt between each
+/// sector's DRQ instead of polling it. Mirrors `Bus::read`.
+async fn read_chunk_async(bus: u8, drive: u8, block: u64, sectors: u8, buf: &mut [u8]) -> Result<(), ()> {
+    {
+        let mut buses = BUSES.lock();
+        let b = &mut buses[bus as usize];
+        b.setup_pio(drive, block, sectors)?;
+        let cmd = if b.lba48[drive as usize] { Command::ReadExt } else { Command::Read };
+        b.issue_command(cmd)?;
+    }
+    for sector in buf.chunks_mut(BLOCK_SIZE) {
+        WaitForInterrupt::new(bus).await?;
+        let mut buses = BUSES.lock();
+        let b = &mut buses[bus as usize];
+        b.clear_interrupt();
+        for chunk in sector.chunks_mut(2) {
+            let data = b.read_data().to_le_bytes();
+            chunk.clone_from_slice(&data);
+        }
+    }
+    if BUSES.lock()[bus as usize].is_error() {
+        Err(())
+    } else {
+        Ok(())
+    }
+}
+
+/// Issues a single chunked write, awaiting the bus interrupt between each
+/// sector's DRQ instead of polling it. Mirrors `Bus::write`.
+async fn write_chunk_async(bus: u8, drive: u8, block: u64, sectors: u8, buf: &[u8]) -> Result<(), ()> {
+    {
+        let mut buses = BUSES.lock();
+        let b = &mut buses[bus as usize];
+        b.setup_pio(drive, block, sectors)?;
+        let cmd = if b.lba48[drive as usize] { Command::WriteExt } else { Command::Write };
+        b.issue_command(cmd)?;
+    }
+    for sector in buf.chunks(BLOCK_SIZE) {
+        WaitForInterrupt::new(bus).await?;
+        let mut buses = BUSES.lock();
+        let b = &mut buses[bus as usize];
+        b.clear_interrupt();
+        for chunk in sector.chunks(2) {
+            let data = u16::from_le_bytes(chunk.try_into().unwrap());
+            b.write_data(data);
+        }
+    }
+    if BUSES.lock()[bus as usize].is_error() {
+        Err(())
+    } else {
+        Ok(())
+    }
+}
+
+/// Async counterpart to `read_ata`: yields to the executor instead of
+/// busy-waiting. Requests against the same bus are serialized by
+/// `BUS_QUEUES`.
+pub async fn read_ata_async(bus: u8, drive: u8, block: u64, buf: &mut [u8]) -> Result<(), ()> {
+    assert_eq!(buf.len() % BLOCK_SIZE, 0, "buffer must be a multiple of BLOCK_SIZE");
+    AcquireBus::new(bus).await?;
+    let mut lba = block;
+    let mut result = Ok(());
+    for chunk in buf.chunks_mut(MAX_SECTORS_PER_COMMAND * BLOCK_SIZE) {
+        let sectors = chunk.len() / BLOCK_SIZE;
+        if read_chunk_async(bus, drive, lba, sectors as u8, chunk).await.is_err() {
+            result = Err(());
+            break;
+        }
+        lba += sectors as u64;
+    }
+    BUS_QUEUES[bus as usize].release();
+    result
+}
+
+/// Async counterpart to `write_ata`. See `read_ata_async`.
+pub async fn write_ata_async(bus: u8, drive: u8, block: u64, buf: &[u8]) -> Result<(), ()> {
+    assert_eq!(buf.len() % BLOCK_SIZE, 0, "buffer must be a multiple of BLOCK_SIZE");
+    AcquireBus::new(bus).await?;
+    let mut lba = block;
+    let mut result = Ok(());
+    for chunk in buf.chunks(MAX_SECTORS_PER_COMMAND * BLOCK_SIZE) {
+        let sectors = chunk.len() / BLOCK_SIZE;
+        if write_chunk_async(bus, drive, lba, sectors as u8, chunk).await.is_err() {
+            result = Err(());
+            break;
+        }
+        lba += sectors as u64;
+    }
+    BUS_QUEUES[bus as usize].release();
+    result
+}
+
+/// Reads the bootable drive's block 0 through the async path once at boot,
+/// spawned as a real `Executor` task from `main.rs`. Until IRQ 14/15 are
+/// routed to `handle_interrupt`, this always exhausts `WaitForInterrupt`'s
+/// poll budget and logs failure.
+pub async fn probe_boot_drive_async() {
+    let drive = DRIVES.lock().iter().find(|d| d.dsk == 0).cloned();
+    if let Some(drive) = drive {
+        let mut buf = [0u8; BLOCK_SIZE];
+        match read_ata_async(drive.bus, drive.dsk, 0, &mut buf).await {
+            Ok(()) => println!("ATA: async read of boot drive block 0 succeeded"),
+            Err(()) => println!("ATA: async read of boot drive block 0 failed"),
+        }
+    }
+}
+
+// --- Tests -------------------------------------------------------------
+//
+// No real drive or IDT is available under the test runner, so these drive
+// `WaitForInterrupt`/`AcquireBus`/`BusQueue` directly by hand-polling the
+// futures and calling `handle_interrupt`, instead of exercising the
+// hardware-backed `read_ata_async`/`write_ata_async`.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker { noop_raw_waker() }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    fn noop_waker() -> Waker {
+        unsafe { Waker::from_raw(noop_raw_waker()) }
+    }
+
+    #[test_case]
+    fn wait_for_interrupt_parks_then_completes_on_handle_interrupt() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = WaitForInterrupt::new(0);
+
+        assert_eq!(Future::poll(Pin::new(&mut fut), &mut cx), Poll::Pending);
+
+        handle_interrupt(0);
+
+        assert_eq!(Future::poll(Pin::new(&mut fut), &mut cx), Poll::Ready(Ok(())));
+    }
+
+    #[test_case]
+    fn bus_queue_try_acquire_blocks_until_released() {
+        let queue = BusQueue::new();
+        assert!(queue.try_acquire());
+        assert!(!queue.try_acquire());
+
+        queue.release();
+        assert!(queue.try_acquire());
+    }
+
+    #[test_case]
+    fn acquire_bus_future_completes_once_the_queue_is_released() {
+        let bus = 1;
+        assert!(BUS_QUEUES[bus as usize].try_acquire());
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = AcquireBus::new(bus);
+
+        assert_eq!(Future::poll(Pin::new(&mut fut), &mut cx), Poll::Pending);
+
+        BUS_QUEUES[bus as usize].release();
+
+        assert_eq!(Future::poll(Pin::new(&mut fut), &mut cx), Poll::Ready(Ok(())));
+    }
+}
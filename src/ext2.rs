@@ -0,0 +1,288 @@
+use core::convert::TryInto;
+use alloc::vec::Vec;
+use alloc::vec;
+use alloc::string::String;
+use alloc::string::ToString;
+use crate::ata;
+use crate::println;
+
+/// Byte offset of the ext2 superblock within the volume, regardless of the
+/// filesystem's own block size.
+const SUPERBLOCK_OFFSET: usize = 1024;
+const MAGIC: u16 = 0xEF53;
+
+/// Size of an ext2 block-group descriptor in bytes.
+const GROUP_DESC_SIZE: usize = 32;
+
+/// Default inode size for filesystems with no extended superblock fields
+/// (`s_rev_level == 0`).
+const DEFAULT_INODE_SIZE: u16 = 128;
+
+const DIRECT_POINTERS: usize = 12;
+const FILE_TYPE_DIR: u16 = 0x4000;
+
+struct SuperBlock {
+    inodes_count: u32,
+    blocks_count: u32,
+    first_data_block: u32,
+    block_size: u32,
+    blocks_per_group: u32,
+    inodes_per_group: u32,
+    inode_size: u16,
+}
+
+impl SuperBlock {
+    fn parse(buf: &[u8]) -> Result<Self, ()> {
+        let magic = u16::from_le_bytes(buf[56..58].try_into().unwrap());
+        if magic != MAGIC {
+            return Err(());
+        }
+
+        let rev_level = u32::from_le_bytes(buf[76..80].try_into().unwrap());
+        let inode_size = if rev_level >= 1 {
+            u16::from_le_bytes(buf[88..90].try_into().unwrap())
+        } else {
+            DEFAULT_INODE_SIZE
+        };
+
+        let log_block_size = u32::from_le_bytes(buf[24..28].try_into().unwrap());
+
+        Ok(Self {
+            inodes_count: u32::from_le_bytes(buf[0..4].try_into().unwrap()),
+            blocks_count: u32::from_le_bytes(buf[4..8].try_into().unwrap()),
+            first_data_block: u32::from_le_bytes(buf[20..24].try_into().unwrap()),
+            block_size: 1024 << log_block_size,
+            blocks_per_group: u32::from_le_bytes(buf[32..36].try_into().unwrap()),
+            inodes_per_group: u32::from_le_bytes(buf[40..44].try_into().unwrap()),
+            inode_size,
+        })
+    }
+
+    fn group_count(&self) -> u32 {
+        (self.blocks_count + self.blocks_per_group - 1) / self.blocks_per_group
+    }
+}
+
+fn lba_of_block(block_size: u32, block: u32) -> u64 {
+    block as u64 * (block_size / ata::BLOCK_SIZE as u32) as u64
+}
+
+fn read_raw_block(bus: u8, dsk: u8, block_size: u32, block: u32) -> Result<Vec<u8>, ()> {
+    let mut buf = vec![0u8; block_size as usize];
+    ata::read_ata(bus, dsk, lba_of_block(block_size, block), &mut buf)?;
+    Ok(buf)
+}
+
+struct GroupDescriptor {
+    inode_table: u32,
+}
+
+impl GroupDescriptor {
+    fn parse(buf: &[u8]) -> Self {
+        Self {
+            inode_table: u32::from_le_bytes(buf[8..12].try_into().unwrap()),
+        }
+    }
+}
+
+struct Inode {
+    mode: u16,
+    size: u32,
+    block: [u32; 15],
+}
+
+impl Inode {
+    fn parse(buf: &[u8]) -> Self {
+        let mode = u16::from_le_bytes(buf[0..2].try_into().unwrap());
+        let size = u32::from_le_bytes(buf[4..8].try_into().unwrap());
+        let mut block = [0u32; 15];
+        for (i, ptr) in block.iter_mut().enumerate() {
+            let off = 40 + i * 4;
+            *ptr = u32::from_le_bytes(buf[off..off + 4].try_into().unwrap());
+        }
+        Self { mode, size, block }
+    }
+
+    fn is_dir(&self) -> bool {
+        self.mode & 0xF000 == FILE_TYPE_DIR
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DirEntry {
+    pub inode: u32,
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// A mounted, read-only ext2 volume.
+pub struct Ext2 {
+    bus: u8,
+    dsk: u8,
+    sb: SuperBlock,
+    groups: Vec<GroupDescriptor>,
+}
+
+pub const ROOT_INODE: u32 = 2;
+
+impl Ext2 {
+    fn read_block(&self, block: u32) -> Result<Vec<u8>, ()> {
+        read_raw_block(self.bus, self.dsk, self.sb.block_size, block)
+    }
+
+    fn read_inode(&self, inum: u32) -> Result<Inode, ()> {
+        let index = (inum - 1) % self.sb.inodes_per_group;
+        let group = (inum - 1) / self.sb.inodes_per_group;
+        let group = self.groups.get(group as usize).ok_or(())?;
+
+        let byte_off = index as usize * self.sb.inode_size as usize;
+        let block = group.inode_table + (byte_off / self.sb.block_size as usize) as u32;
+        let within_block = byte_off % self.sb.block_size as usize;
+
+        let buf = self.read_block(block)?;
+        Ok(Inode::parse(&buf[within_block..within_block + self.sb.inode_size as usize]))
+    }
+
+    /// Resolves the `index`th block (0-based) of a file's contents,
+    /// following direct, single- and double-indirect pointers.
+    fn data_block(&self, inode: &Inode, index: usize) -> Result<Option<u32>, ()> {
+        if index < DIRECT_POINTERS {
+            let block = inode.block[index];
+            return Ok(if block == 0 { None } else { Some(block) });
+        }
+
+        let ptrs_per_block = self.sb.block_size as usize / 4;
+        let index = index - DIRECT_POINTERS;
+
+        if index < ptrs_per_block {
+            return self.indirect_pointer(inode.block[12], index);
+        }
+        let index = index - ptrs_per_block;
+
+        if index < ptrs_per_block * ptrs_per_block {
+            if inode.block[13] == 0 {
+                return Ok(None);
+            }
+            let outer = self.read_block(inode.block[13])?;
+            let outer_index = index / ptrs_per_block;
+            let off = outer_index * 4;
+            let mid_block = u32::from_le_bytes(outer[off..off + 4].try_into().unwrap());
+            return self.indirect_pointer(mid_block, index % ptrs_per_block);
+        }
+
+        // Triple-indirect blocks are not supported.
+        Err(())
+    }
+
+    fn indirect_pointer(&self, block: u32, index: usize) -> Result<Option<u32>, ()> {
+        if block == 0 {
+            return Ok(None);
+        }
+        let table = self.read_block(block)?;
+        let off = index * 4;
+        let ptr = u32::from_le_bytes(table[off..off + 4].try_into().unwrap());
+        Ok(if ptr == 0 { None } else { Some(ptr) })
+    }
+
+    /// Streams the full contents of `inum` into `buf`, returning the number
+    /// of bytes copied (`min(buf.len(), file size)`).
+    ///
+    /// A `None` block pointer is a sparse-file hole, not an error: ext2
+    /// images may legitimately have zero pointers mid-file, and those
+    /// regions read back as zeroes.
+    pub fn read_file(&self, inum: u32, buf: &mut [u8]) -> Result<usize, ()> {
+        let inode = self.read_inode(inum)?;
+        let len = core::cmp::min(buf.len(), inode.size as usize);
+
+        let mut copied = 0;
+        let mut block_index = 0;
+        while copied < len {
+            let take = core::cmp::min(self.sb.block_size as usize, len - copied);
+            match self.data_block(&inode, block_index)? {
+                Some(block) => {
+                    let block_buf = self.read_block(block)?;
+                    buf[copied..copied + take].clone_from_slice(&block_buf[..take]);
+                }
+                None => {
+                    buf[copied..copied + take].fill(0);
+                }
+            }
+            copied += take;
+            block_index += 1;
+        }
+        Ok(copied)
+    }
+
+    /// Walks the linked directory-entry records of directory inode `inum`.
+    pub fn read_dir(&self, inum: u32) -> Result<Vec<DirEntry>, ()> {
+        let inode = self.read_inode(inum)?;
+        if !inode.is_dir() {
+            return Err(());
+        }
+
+        let mut entries = Vec::new();
+        let mut remaining = inode.size as usize;
+        let mut block_index = 0;
+        while remaining > 0 {
+            let block = self.data_block(&inode, block_index)?.ok_or(())?;
+            let buf = self.read_block(block)?;
+
+            let mut pos = 0;
+            while pos < buf.len() {
+                let entry_inode = u32::from_le_bytes(buf[pos..pos + 4].try_into().unwrap());
+                let rec_len = u16::from_le_bytes(buf[pos + 4..pos + 6].try_into().unwrap()) as usize;
+                if rec_len == 0 {
+                    break;
+                }
+                let name_len = buf[pos + 6] as usize;
+                let file_type = buf[pos + 7];
+                if entry_inode != 0 {
+                    let name = String::from_utf8_lossy(&buf[pos + 8..pos + 8 + name_len]).to_string();
+                    if name != "." && name != ".." {
+                        entries.push(DirEntry {
+                            inode: entry_inode,
+                            name,
+                            is_dir: file_type == 2,
+                        });
+                    }
+                }
+                pos += rec_len;
+            }
+
+            remaining = remaining.saturating_sub(buf.len());
+            block_index += 1;
+        }
+        Ok(entries)
+    }
+}
+
+/// Mounts the ext2 filesystem on `bus`/`dsk`, reading its superblock and
+/// block-group descriptor table.
+pub fn mount(bus: u8, dsk: u8) -> Result<Ext2, ()> {
+    let mut raw = [0u8; 1024];
+    ata::read_ata(bus, dsk, (SUPERBLOCK_OFFSET / ata::BLOCK_SIZE) as u64, &mut raw)?;
+    let sb = SuperBlock::parse(&raw)?;
+
+    let bgdt_block = sb.first_data_block + 1;
+    let mut groups = Vec::new();
+    let mut remaining = sb.group_count() as usize;
+    let mut block = bgdt_block;
+    while remaining > 0 {
+        let buf = read_raw_block(bus, dsk, sb.block_size, block)?;
+        let per_block = buf.len() / GROUP_DESC_SIZE;
+        for i in 0..per_block {
+            if remaining == 0 {
+                break;
+            }
+            let off = i * GROUP_DESC_SIZE;
+            groups.push(GroupDescriptor::parse(&buf[off..off + GROUP_DESC_SIZE]));
+            remaining -= 1;
+        }
+        block += 1;
+    }
+
+    println!("EXT2: mounted volume with {} inodes, {} blocks ({} bytes/block)",
+            sb.inodes_count, sb.blocks_count, sb.block_size);
+
+    Ok(Ext2 { bus, dsk, sb, groups })
+}
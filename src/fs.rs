@@ -1,19 +1,148 @@
 use core::str;
+use core::convert::TryInto;
+use bit_field::BitField;
+use lazy_static::lazy_static;
+use spin::Mutex;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::string::ToString;
 use crate::ata;
 use crate::{print, println};
 use crate::KERNEL_SIZE;
 
-const SUPERBLOCK_ADDR: u32 = (KERNEL_SIZE / ata::BLOCK_SIZE) as u32;
+const SUPERBLOCK_ADDR: u64 = (KERNEL_SIZE / ata::BLOCK_SIZE) as u64;
 const SIGNATURE: &[u8; 8] = b"COS FSYS";
 
-struct SuperBlock<'a> {
-    signature: &'a [u8; 8],
+/// Default number of inode slots carved out at format time.
+const DEFAULT_INODE_COUNT: u32 = 1024;
+
+/// Inodes are packed into fixed-size slots so the inode table can be
+/// indexed directly instead of scanned.
+const INODE_SIZE: usize = 64;
+const DIRECT_POINTERS: usize = 12;
+const POINTERS_PER_BLOCK: usize = ata::BLOCK_SIZE / 4;
+
+/// Directory entries are fixed-size slots too: a 4-byte inode number, a
+/// 1-byte name length, and inline name bytes. `name_len == 0` marks an
+/// unused slot (entries always have a non-empty name).
+const DIRENT_SIZE: usize = 64;
+const MAX_NAME_LEN: usize = DIRENT_SIZE - 5;
+
+pub const ROOT_INODE: u32 = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum InodeKind {
+    Free = 0,
+    File = 1,
+    Dir = 2,
+}
+
+impl InodeKind {
+    fn from_u8(b: u8) -> Self {
+        match b {
+            1 => InodeKind::File,
+            2 => InodeKind::Dir,
+            _ => InodeKind::Free,
+        }
+    }
+}
+
+/// An on-disk inode: direct block pointers cover small files, with a single
+/// indirect block extending that reach for larger ones.
+#[derive(Clone, Copy)]
+struct Inode {
+    kind: InodeKind,
+    size: u64,
+    direct: [u32; DIRECT_POINTERS],
+    indirect: u32,
 }
 
-impl SuperBlock<'_> {
-    fn new() -> Self {
+impl Inode {
+    fn empty() -> Self {
         Self {
-            signature: SIGNATURE,
+            kind: InodeKind::Free,
+            size: 0,
+            direct: [0; DIRECT_POINTERS],
+            indirect: 0,
+        }
+    }
+
+    fn to_bytes(&self) -> [u8; INODE_SIZE] {
+        let mut buf = [0u8; INODE_SIZE];
+        buf[0] = self.kind as u8;
+        buf[1..9].clone_from_slice(&self.size.to_le_bytes());
+        for (i, block) in self.direct.iter().enumerate() {
+            let off = 9 + i * 4;
+            buf[off..off + 4].clone_from_slice(&block.to_le_bytes());
+        }
+        let off = 9 + DIRECT_POINTERS * 4;
+        buf[off..off + 4].clone_from_slice(&self.indirect.to_le_bytes());
+        buf
+    }
+
+    fn from_bytes(buf: &[u8]) -> Self {
+        let kind = InodeKind::from_u8(buf[0]);
+        let size = u64::from_le_bytes(buf[1..9].try_into().unwrap());
+        let mut direct = [0u32; DIRECT_POINTERS];
+        for (i, block) in direct.iter_mut().enumerate() {
+            let off = 9 + i * 4;
+            *block = u32::from_le_bytes(buf[off..off + 4].try_into().unwrap());
+        }
+        let off = 9 + DIRECT_POINTERS * 4;
+        let indirect = u32::from_le_bytes(buf[off..off + 4].try_into().unwrap());
+        Self { kind, size, direct, indirect }
+    }
+}
+
+/// Layout is expressed in filesystem-relative block numbers, offset from
+/// `SUPERBLOCK_ADDR` before hitting the drive.
+struct SuperBlock {
+    signature: [u8; 8],
+    block_size: u32,
+    block_count: u32,
+    bitmap_block: u32,
+    bitmap_blocks: u32,
+    inode_table_block: u32,
+    inode_count: u32,
+    first_data_block: u32,
+}
+
+impl SuperBlock {
+    /// Computes a fresh layout sized for `drive`.
+    fn for_drive(drive: &ata::Drive) -> Self {
+        let block_size = drive.block_size();
+        // The on-disk superblock only budgets 32 bits for the block count;
+        // COS's own format isn't meant to span LBA48-sized drives yet, so
+        // clamp rather than truncate: truncating could wrap to a value
+        // smaller than `first_data_block`, leaving no room for any data
+        // blocks at all.
+        let real_block_count = drive.block_count();
+        if real_block_count > u32::MAX as u64 {
+            println!("FS: drive has {} blocks, clamping to {} (u32::MAX)", real_block_count, u32::MAX);
+        }
+        let block_count = real_block_count.min(u32::MAX as u64) as u32;
+        let inode_count = DEFAULT_INODE_COUNT;
+
+        let bitmap_block = 1;
+        let bitmap_bytes = (block_count as usize + 7) / 8;
+        let bitmap_blocks = ((bitmap_bytes + ata::BLOCK_SIZE - 1) / ata::BLOCK_SIZE) as u32;
+
+        let inode_table_block = bitmap_block + bitmap_blocks;
+        let inode_table_bytes = inode_count as usize * INODE_SIZE;
+        let inode_table_blocks = ((inode_table_bytes + ata::BLOCK_SIZE - 1) / ata::BLOCK_SIZE) as u32;
+
+        let first_data_block = inode_table_block + inode_table_blocks;
+
+        Self {
+            signature: *SIGNATURE,
+            block_size,
+            block_count,
+            bitmap_block,
+            bitmap_blocks,
+            inode_table_block,
+            inode_count,
+            first_data_block,
         }
     }
 
@@ -34,19 +163,288 @@ impl SuperBlock<'_> {
     fn write(&self, bus: u8, dsk: u8) -> Result<(), ()> {
         let mut buf = [0u8; ata::BLOCK_SIZE];
 
-        buf[..8].clone_from_slice(self.signature);
+        buf[0..8].clone_from_slice(&self.signature);
+        buf[8..12].clone_from_slice(&self.block_size.to_le_bytes());
+        buf[12..16].clone_from_slice(&self.block_count.to_le_bytes());
+        buf[16..20].clone_from_slice(&self.bitmap_block.to_le_bytes());
+        buf[20..24].clone_from_slice(&self.bitmap_blocks.to_le_bytes());
+        buf[24..28].clone_from_slice(&self.inode_table_block.to_le_bytes());
+        buf[28..32].clone_from_slice(&self.inode_count.to_le_bytes());
+        buf[32..36].clone_from_slice(&self.first_data_block.to_le_bytes());
 
         ata::write_ata(bus, dsk, SUPERBLOCK_ADDR, &buf)?;
         Ok(())
     }
+
+    /// Reads superblock data back from the drive
+    fn read(bus: u8, dsk: u8) -> Result<Self, ()> {
+        let mut buf = [0u8; ata::BLOCK_SIZE];
+        ata::read_ata(bus, dsk, SUPERBLOCK_ADDR, &mut buf)?;
+        Ok(Self {
+            signature: buf[0..8].try_into().unwrap(),
+            block_size: u32::from_le_bytes(buf[8..12].try_into().unwrap()),
+            block_count: u32::from_le_bytes(buf[12..16].try_into().unwrap()),
+            bitmap_block: u32::from_le_bytes(buf[16..20].try_into().unwrap()),
+            bitmap_blocks: u32::from_le_bytes(buf[20..24].try_into().unwrap()),
+            inode_table_block: u32::from_le_bytes(buf[24..28].try_into().unwrap()),
+            inode_count: u32::from_le_bytes(buf[28..32].try_into().unwrap()),
+            first_data_block: u32::from_le_bytes(buf[32..36].try_into().unwrap()),
+        })
+    }
+}
+
+/// Mounted filesystem state: the drive it lives on plus its superblock.
+struct Fs {
+    bus: u8,
+    dsk: u8,
+    sb: SuperBlock,
+}
+
+impl Fs {
+    fn lba(&self, fs_block: u32) -> u64 {
+        SUPERBLOCK_ADDR + fs_block as u64
+    }
+
+    fn read_block(&self, fs_block: u32) -> Result<[u8; ata::BLOCK_SIZE], ()> {
+        let mut buf = [0u8; ata::BLOCK_SIZE];
+        ata::read_ata(self.bus, self.dsk, self.lba(fs_block), &mut buf)?;
+        Ok(buf)
+    }
+
+    fn write_block(&self, fs_block: u32, buf: &[u8; ata::BLOCK_SIZE]) -> Result<(), ()> {
+        ata::write_ata(self.bus, self.dsk, self.lba(fs_block), buf)
+    }
+
+    /// Scans the free-block bitmap for a clear bit, sets it, and returns the
+    /// block it now owns.
+    fn alloc_block(&self) -> Result<u32, ()> {
+        for bi in 0..self.sb.bitmap_blocks {
+            let mut buf = self.read_block(self.sb.bitmap_block + bi)?;
+            for byte_idx in 0..buf.len() {
+                if buf[byte_idx] == 0xFF {
+                    continue;
+                }
+                for bit in 0..8 {
+                    let block_num = bi * ata::BLOCK_SIZE as u32 * 8 + byte_idx as u32 * 8 + bit as u32;
+                    if block_num >= self.sb.block_count {
+                        break;
+                    }
+                    if !buf[byte_idx].get_bit(bit) {
+                        buf[byte_idx].set_bit(bit, true);
+                        self.write_block(self.sb.bitmap_block + bi, &buf)?;
+                        return Ok(block_num);
+                    }
+                }
+            }
+        }
+        Err(())
+    }
+
+    fn free_block(&self, block_num: u32) -> Result<(), ()> {
+        let bi = block_num / (ata::BLOCK_SIZE as u32 * 8);
+        let bit_offset = (block_num % (ata::BLOCK_SIZE as u32 * 8)) as usize;
+        let mut buf = self.read_block(self.sb.bitmap_block + bi)?;
+        buf[bit_offset / 8].set_bit(bit_offset % 8, false);
+        self.write_block(self.sb.bitmap_block + bi, &buf)
+    }
+
+    /// Zeroes the bitmap and inode table, then marks the metadata blocks
+    /// (superblock, bitmap, inode table) as permanently allocated so the
+    /// data-block allocator never hands them out.
+    fn reserve_metadata_blocks(&self) -> Result<(), ()> {
+        let zero = [0u8; ata::BLOCK_SIZE];
+        for bi in 0..self.sb.bitmap_blocks {
+            self.write_block(self.sb.bitmap_block + bi, &zero)?;
+        }
+        let inode_table_blocks = self.sb.first_data_block - self.sb.inode_table_block;
+        for bi in 0..inode_table_blocks {
+            self.write_block(self.sb.inode_table_block + bi, &zero)?;
+        }
+
+        for block in 0..self.sb.first_data_block {
+            let bi = block / (ata::BLOCK_SIZE as u32 * 8);
+            let bit_offset = (block % (ata::BLOCK_SIZE as u32 * 8)) as usize;
+            let mut buf = self.read_block(self.sb.bitmap_block + bi)?;
+            buf[bit_offset / 8].set_bit(bit_offset % 8, true);
+            self.write_block(self.sb.bitmap_block + bi, &buf)?;
+        }
+        Ok(())
+    }
+
+    fn inode_location(&self, inum: u32) -> (u32, usize) {
+        let byte_off = inum as usize * INODE_SIZE;
+        let block = self.sb.inode_table_block + (byte_off / ata::BLOCK_SIZE) as u32;
+        let within_block = byte_off % ata::BLOCK_SIZE;
+        (block, within_block)
+    }
+
+    fn read_inode(&self, inum: u32) -> Result<Inode, ()> {
+        let (block, within_block) = self.inode_location(inum);
+        let buf = self.read_block(block)?;
+        Ok(Inode::from_bytes(&buf[within_block..within_block + INODE_SIZE]))
+    }
+
+    fn write_inode(&self, inum: u32, inode: &Inode) -> Result<(), ()> {
+        let (block, within_block) = self.inode_location(inum);
+        let mut buf = self.read_block(block)?;
+        buf[within_block..within_block + INODE_SIZE].clone_from_slice(&inode.to_bytes());
+        self.write_block(block, &buf)
+    }
+
+    fn alloc_inode(&self) -> Result<u32, ()> {
+        for inum in 0..self.sb.inode_count {
+            if self.read_inode(inum)?.kind == InodeKind::Free {
+                return Ok(inum);
+            }
+        }
+        Err(())
+    }
+
+    /// Resolves (and, if `allocate`, creates) the data block backing a byte
+    /// offset inside an inode's contents. Returns `0` for a sparse hole when
+    /// `!allocate`; a freshly allocated block is zero-filled before it's
+    /// handed back.
+    fn data_block(&self, inum: u32, inode: &mut Inode, offset: usize, allocate: bool) -> Result<u32, ()> {
+        let block_index = offset / ata::BLOCK_SIZE;
+
+        if block_index < DIRECT_POINTERS {
+            if inode.direct[block_index] == 0 {
+                if !allocate {
+                    return Ok(0);
+                }
+                inode.direct[block_index] = self.alloc_block()?;
+                self.write_block(inode.direct[block_index], &[0u8; ata::BLOCK_SIZE])?;
+                self.write_inode(inum, inode)?;
+            }
+            return Ok(inode.direct[block_index]);
+        }
+
+        let indirect_index = block_index - DIRECT_POINTERS;
+        if indirect_index >= POINTERS_PER_BLOCK {
+            return Err(());
+        }
+
+        if inode.indirect == 0 {
+            if !allocate {
+                return Ok(0);
+            }
+            inode.indirect = self.alloc_block()?;
+            self.write_inode(inum, inode)?;
+            self.write_block(inode.indirect, &[0u8; ata::BLOCK_SIZE])?;
+        }
+
+        let mut table = self.read_block(inode.indirect)?;
+        let off = indirect_index * 4;
+        let pointer = u32::from_le_bytes(table[off..off + 4].try_into().unwrap());
+        if pointer != 0 {
+            return Ok(pointer);
+        }
+        if !allocate {
+            return Ok(0);
+        }
+        let new_block = self.alloc_block()?;
+        self.write_block(new_block, &[0u8; ata::BLOCK_SIZE])?;
+        table[off..off + 4].clone_from_slice(&new_block.to_le_bytes());
+        self.write_block(inode.indirect, &table)?;
+        Ok(new_block)
+    }
+
+    fn read_dirent_slot(buf: &[u8; DIRENT_SIZE]) -> Option<(u32, String)> {
+        let name_len = buf[4] as usize;
+        if name_len == 0 {
+            return None;
+        }
+        let inode = u32::from_le_bytes(buf[0..4].try_into().unwrap());
+        let name = String::from_utf8_lossy(&buf[5..5 + name_len]).to_string();
+        Some((inode, name))
+    }
+
+    fn write_dirent_slot(inode: u32, name: &str) -> [u8; DIRENT_SIZE] {
+        let mut buf = [0u8; DIRENT_SIZE];
+        buf[0..4].clone_from_slice(&inode.to_le_bytes());
+        buf[4] = name.len() as u8;
+        buf[5..5 + name.len()].clone_from_slice(name.as_bytes());
+        buf
+    }
+
+    /// Iterates over a directory's entry slots, calling `f(slot_offset, entry)`
+    /// for every occupied one.
+    fn for_each_dirent(&self, dir_inum: u32, dir: &Inode, mut f: impl FnMut(usize, u32, &str)) -> Result<(), ()> {
+        let slots = dir.size as usize / DIRENT_SIZE;
+        let mut dir = *dir;
+        for slot in 0..slots {
+            let offset = slot * DIRENT_SIZE;
+            let block = self.data_block(dir_inum, &mut dir, offset, false)?;
+            let buf = self.read_block(block)?;
+            let within = offset % ata::BLOCK_SIZE;
+            let slot_buf: [u8; DIRENT_SIZE] = buf[within..within + DIRENT_SIZE].try_into().unwrap();
+            if let Some((inum, name)) = Self::read_dirent_slot(&slot_buf) {
+                f(offset, inum, &name);
+            }
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, parent: u32, name: &str) -> Result<Option<u32>, ()> {
+        let dir = self.read_inode(parent)?;
+        let mut found = None;
+        self.for_each_dirent(parent, &dir, |_, inum, entry_name| {
+            if entry_name == name {
+                found = Some(inum);
+            }
+        })?;
+        Ok(found)
+    }
+
+    fn add_dirent(&self, parent: u32, name: &str, inode: u32) -> Result<(), ()> {
+        let mut dir = self.read_inode(parent)?;
+        let offset = dir.size as usize;
+        let block = self.data_block(parent, &mut dir, offset, true)?;
+        let mut buf = self.read_block(block)?;
+        let within = offset % ata::BLOCK_SIZE;
+        buf[within..within + DIRENT_SIZE].clone_from_slice(&Self::write_dirent_slot(inode, name));
+        self.write_block(block, &buf)?;
+        dir.size += DIRENT_SIZE as u64;
+        self.write_inode(parent, &dir)
+    }
+
+    fn remove_dirent(&self, parent: u32, name: &str) -> Result<(), ()> {
+        let dir = self.read_inode(parent)?;
+        let mut target_offset = None;
+        self.for_each_dirent(parent, &dir, |offset, _, entry_name| {
+            if entry_name == name {
+                target_offset = Some(offset);
+            }
+        })?;
+        let offset = target_offset.ok_or(())?;
+        let mut dir_mut = dir;
+        let block = self.data_block(parent, &mut dir_mut, offset, false)?;
+        let mut buf = self.read_block(block)?;
+        let within = offset % ata::BLOCK_SIZE;
+        buf[within..within + DIRENT_SIZE].clone_from_slice(&[0u8; DIRENT_SIZE]);
+        self.write_block(block, &buf)
+    }
+}
+
+lazy_static! {
+    static ref FS: Mutex<Option<Fs>> = Mutex::new(None);
 }
 
 /// Sets up the COS Filesystem on a drive
 /// Call if a drive is unformatted
 fn format_ata(drive: &ata::Drive) {
-    let sb = SuperBlock::new();
+    let sb = SuperBlock::for_drive(drive);
     sb.write(drive.bus, drive.dsk).expect("FS: Failed to write super block");
     println!("FS: Wrote super block");
+
+    let fs = Fs { bus: drive.bus, dsk: drive.dsk, sb };
+    fs.reserve_metadata_blocks().expect("FS: Failed to initialize free-block bitmap");
+
+    let root = Inode { kind: InodeKind::Dir, size: 0, direct: [0; DIRECT_POINTERS], indirect: 0 };
+    fs.write_inode(ROOT_INODE, &root).expect("FS: Failed to write root inode");
+    println!("FS: Created root directory");
+
+    *FS.lock() = Some(fs);
 }
 
 /// Initializes the COS Filesystem for a mounted drive
@@ -61,6 +459,192 @@ pub fn init(drive: &ata::Drive) {
         format_ata(drive);
     } else {
         println!("FS: Drive is ok. No need to format");
+        let sb = SuperBlock::read(drive.bus, drive.dsk).expect("FS: Failed to read super block");
+        *FS.lock() = Some(Fs { bus: drive.bus, dsk: drive.dsk, sb });
+    }
+}
+
+/// Creates a new file or directory named `name` inside `parent`.
+pub fn create(parent: u32, name: &str, kind: InodeKind) -> Result<u32, ()> {
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        return Err(());
+    }
+    let fs_guard = FS.lock();
+    let fs = fs_guard.as_ref().ok_or(())?;
+    if fs.read_inode(parent)?.kind != InodeKind::Dir {
+        return Err(());
+    }
+    if fs.lookup(parent, name)?.is_some() {
+        return Err(());
+    }
+    let inum = fs.alloc_inode()?;
+    let inode = Inode { kind, size: 0, direct: [0; DIRECT_POINTERS], indirect: 0 };
+    fs.write_inode(inum, &inode)?;
+    fs.add_dirent(parent, name, inum)?;
+    Ok(inum)
+}
+
+/// Looks up `name` inside `parent`, returning its inode number.
+pub fn open(parent: u32, name: &str) -> Result<u32, ()> {
+    let fs_guard = FS.lock();
+    let fs = fs_guard.as_ref().ok_or(())?;
+    fs.lookup(parent, name)?.ok_or(())
+}
+
+/// Reads up to `buf.len()` bytes from `inode` starting at `offset`. A
+/// sparse hole reads back as zeroes rather than erroring.
+pub fn read(inode: u32, offset: usize, buf: &mut [u8]) -> Result<usize, ()> {
+    let fs_guard = FS.lock();
+    let fs = fs_guard.as_ref().ok_or(())?;
+    let file = fs.read_inode(inode)?;
+
+    let end = core::cmp::min(offset + buf.len(), file.size as usize);
+    if offset >= end {
+        return Ok(0);
     }
 
+    let mut read_total = 0;
+    let mut file = file;
+    let mut pos = offset;
+    while pos < end {
+        let block = fs.data_block(inode, &mut file, pos, false)?;
+        let within = pos % ata::BLOCK_SIZE;
+        let take = core::cmp::min(ata::BLOCK_SIZE - within, end - pos);
+        if block == 0 {
+            buf[read_total..read_total + take].fill(0);
+        } else {
+            let block_buf = fs.read_block(block)?;
+            buf[read_total..read_total + take].clone_from_slice(&block_buf[within..within + take]);
+        }
+        pos += take;
+        read_total += take;
+    }
+    Ok(read_total)
+}
+
+/// Writes `buf` into `inode` starting at `offset`, growing it as needed.
+pub fn write(inode: u32, offset: usize, buf: &[u8]) -> Result<usize, ()> {
+    let fs_guard = FS.lock();
+    let fs = fs_guard.as_ref().ok_or(())?;
+    let mut file = fs.read_inode(inode)?;
+
+    let mut written = 0;
+    let mut pos = offset;
+    let end = offset + buf.len();
+    while pos < end {
+        let block = fs.data_block(inode, &mut file, pos, true)?;
+        let within = pos % ata::BLOCK_SIZE;
+        let take = core::cmp::min(ata::BLOCK_SIZE - within, end - pos);
+        let mut block_buf = fs.read_block(block)?;
+        block_buf[within..within + take].clone_from_slice(&buf[written..written + take]);
+        fs.write_block(block, &block_buf)?;
+        pos += take;
+        written += take;
+    }
+
+    if end as u64 > file.size {
+        file.size = end as u64;
+        fs.write_inode(inode, &file)?;
+    }
+    Ok(written)
+}
+
+/// Removes `name` from `parent` and frees its inode. Mirrors POSIX
+/// `unlink`/`rmdir`: a directory can only be removed while empty.
+pub fn unlink(parent: u32, name: &str) -> Result<(), ()> {
+    let fs_guard = FS.lock();
+    let fs = fs_guard.as_ref().ok_or(())?;
+    if fs.read_inode(parent)?.kind != InodeKind::Dir {
+        return Err(());
+    }
+    let inum = fs.lookup(parent, name)?.ok_or(())?;
+    let inode = fs.read_inode(inum)?;
+    if inode.kind == InodeKind::Dir {
+        let mut has_entries = false;
+        fs.for_each_dirent(inum, &inode, |_, _, _| has_entries = true)?;
+        if has_entries {
+            return Err(());
+        }
+    }
+    fs.remove_dirent(parent, name)?;
+
+    for block in inode.direct.iter().filter(|b| **b != 0) {
+        fs.free_block(*block)?;
+    }
+    if inode.indirect != 0 {
+        let table = fs.read_block(inode.indirect)?;
+        for chunk in table.chunks(4) {
+            let ptr = u32::from_le_bytes(chunk.try_into().unwrap());
+            if ptr != 0 {
+                fs.free_block(ptr)?;
+            }
+        }
+        fs.free_block(inode.indirect)?;
+    }
+    fs.write_inode(inum, &Inode::empty())
+}
+
+/// Lists the (inode, name) entries of directory `parent`.
+pub fn readdir(parent: u32) -> Result<Vec<(u32, String)>, ()> {
+    let fs_guard = FS.lock();
+    let fs = fs_guard.as_ref().ok_or(())?;
+    let dir = fs.read_inode(parent)?;
+    let mut entries = Vec::new();
+    fs.for_each_dirent(parent, &dir, |_, inum, name| entries.push((inum, name.to_string())))?;
+    Ok(entries)
+}
+
+// --- Tests -------------------------------------------------------------
+//
+// These cover the pure (de)serialization helpers that pack inodes and
+// directory entries into their fixed-size on-disk slots; no drive or
+// mounted `Fs` is needed to exercise them.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_case]
+    fn inode_roundtrips_through_bytes() {
+        let inode = Inode {
+            kind: InodeKind::File,
+            size: 0x1122_3344_5566_7788,
+            direct: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+            indirect: 42,
+        };
+
+        let bytes = inode.to_bytes();
+        let back = Inode::from_bytes(&bytes);
+
+        assert_eq!(back.kind, inode.kind);
+        assert_eq!(back.size, inode.size);
+        assert_eq!(back.direct, inode.direct);
+        assert_eq!(back.indirect, inode.indirect);
+    }
+
+    #[test_case]
+    fn empty_inode_roundtrips_as_free() {
+        let inode = Inode::empty();
+        let back = Inode::from_bytes(&inode.to_bytes());
+
+        assert_eq!(back.kind, InodeKind::Free);
+        assert_eq!(back.size, 0);
+        assert_eq!(back.direct, [0; DIRECT_POINTERS]);
+        assert_eq!(back.indirect, 0);
+    }
+
+    #[test_case]
+    fn dirent_slot_roundtrips_through_bytes() {
+        let buf = Fs::write_dirent_slot(7, "hello.txt");
+        let (inode, name) = Fs::read_dirent_slot(&buf).expect("occupied slot");
+
+        assert_eq!(inode, 7);
+        assert_eq!(name, "hello.txt");
+    }
+
+    #[test_case]
+    fn zeroed_dirent_slot_reads_back_as_unused() {
+        let buf = [0u8; DIRENT_SIZE];
+        assert!(Fs::read_dirent_slot(&buf).is_none());
+    }
 }
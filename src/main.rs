@@ -8,7 +8,6 @@ extern crate alloc;
 
 use cos::println;
 use cos::task::{executor::Executor, keyboard, Task};
-use cos::ata;
 use bootloader::{entry_point, BootInfo};
 use core::panic::PanicInfo;
 
@@ -26,6 +25,11 @@ fn kernel_main(boot_info: &'static BootInfo) -> ! {
 
     let mut executor = Executor::new();
     executor.spawn(Task::new(keyboard::print_keypresses()));
+    // Runs as a real Executor task so large disk I/O no longer stalls
+    // keyboard input. IRQ 14/15 aren't routed to `ata::handle_interrupt` in
+    // this checkout yet (TODO), so this always times out and logs failure
+    // until that routing lands.
+    executor.spawn(Task::new(cos::ata::probe_boot_drive_async()));
     executor.run();
 }
 